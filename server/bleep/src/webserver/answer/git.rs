@@ -1,6 +1,8 @@
 use anyhow::{Context, Result};
 use chrono::{DateTime, FixedOffset, TimeZone, Utc};
-use gix::{actor::SignatureRef, bstr::ByteSlice, object::tree::diff::Action};
+use gix::{
+    actor::SignatureRef, bstr::ByteSlice, object::tree::diff::Action, traverse::commit::Sorting,
+};
 
 use crate::repo::RepoRef;
 
@@ -12,6 +14,7 @@ pub(super) struct LogSearch {
     pub(super) start_date: Option<DateTime<Utc>>,
     pub(super) end_date: Option<DateTime<Utc>>,
     pub(super) file: Option<String>,
+    pub(super) sorting: Sorting,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
@@ -58,21 +61,48 @@ impl LogSearch {
             (start_date, end_date) = (end_date, start_date);
         }
 
+        // a commit-graph is a huge win for traversal: both parents and
+        // commit time can be read straight out of it, without decoding
+        // every commit object along the way. it's purely an accelerant,
+        // so a repo without one (or with a stale one) still works fine.
+        let commit_graph = git.commit_graph().ok();
+
         let head = || Ok::<_, anyhow::Error>(git.head()?.peel_to_commit_in_place()?);
-        let commits = head()?
+        let ancestors = head()?
             .ancestors()
+            .sorting(self.sorting)
+            .commit_graph(commit_graph)
             .all()?
-            .map(|id| id.unwrap().object().unwrap().into_commit())
-            // we're going through the list in reverse chronological
-            // order, so apply filters accordingly
-            .skip_while(|commit| match end_date {
-                Some(date) => commit.time().unwrap() > date,
-                None => false,
-            })
-            .take_while(|commit| match start_date {
-                Some(date) => commit.time().unwrap() > date,
-                None => true,
-            })
+            .map(|id| id.unwrap().object().unwrap().into_commit());
+
+        // `ByCommitTimeNewestFirst` is the only sorting mode that
+        // guarantees every commit we see is older than the last, so it's
+        // the only one where short-circuiting on the date range is safe.
+        // any other sorting (e.g. topological, which walks in parent
+        // order across merges) can yield an older commit before a newer
+        // one, and `skip_while`/`take_while` would truncate history early.
+        let dated: Box<dyn Iterator<Item = gix::Commit<'_>>> =
+            if matches!(self.sorting, Sorting::ByCommitTimeNewestFirst) {
+                Box::new(
+                    ancestors
+                        .skip_while(move |commit| match end_date {
+                            Some(date) => commit.time().unwrap() > date,
+                            None => false,
+                        })
+                        .take_while(move |commit| match start_date {
+                            Some(date) => commit.time().unwrap() > date,
+                            None => true,
+                        }),
+                )
+            } else {
+                Box::new(ancestors.filter(move |commit| {
+                    let time = commit.time().unwrap();
+                    start_date.map_or(true, |date| time > date)
+                        && end_date.map_or(true, |date| time <= date)
+                }))
+            };
+
+        let commits = dated
             // we implement an AND logic here
             .filter_map(|commit| {
                 let mut decision = match self.author {